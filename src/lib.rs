@@ -1,5 +1,5 @@
 use std::fmt::{Binary, Display, Formatter};
-use std::ops::{BitAnd, BitAndAssign, BitOrAssign, Not, Shr};
+use std::ops::{BitAnd, BitAndAssign, BitOr, BitOrAssign, BitXor, BitXorAssign, Bound, Not, RangeBounds, Shr};
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
 
@@ -77,6 +77,250 @@ impl<B> BitArray<B> where B: Base {
     pub fn zeroes(&self) -> Zeroes<B> {
         Zeroes::new(*self)
     }
+
+    /// Return a new BitArray holding the union (bitwise OR) of this array and `other`.
+    pub fn union(&self, other: &Self) -> Self {
+        BitArray(self.0 | other.0)
+    }
+
+    /// Return a new BitArray holding the intersection (bitwise AND) of this array and `other`.
+    pub fn intersection(&self, other: &Self) -> Self {
+        BitArray(self.0 & other.0)
+    }
+
+    /// Return a new BitArray holding the difference (bits set in this array but not in `other`).
+    pub fn difference(&self, other: &Self) -> Self {
+        BitArray(self.0 & !other.0)
+    }
+
+    /// Return a new BitArray holding the symmetric difference (bitwise XOR) of this array and `other`.
+    pub fn symmetric_difference(&self, other: &Self) -> Self {
+        BitArray(self.0 ^ other.0)
+    }
+
+    /// Return the number of bits set to 1.
+    pub fn count_ones(&self) -> u32 {
+        self.0.count_ones()
+    }
+
+    /// Return the number of bits set to 0.
+    pub fn count_zeros(&self) -> u32 {
+        B::max_len() as u32 - self.count_ones()
+    }
+
+    /// Return the number of bits set to 1 at an index strictly lower than `index`.
+    pub fn rank(&self, index: u8) -> u32 {
+        let mask = if index >= B::max_len() {
+            B::max()
+        } else {
+            B::one_at_index(index).wrapping_sub(B::one())
+        };
+
+        (self.0 & mask).count_ones()
+    }
+
+    /// Return the index of the nth (0-based) bit set to 1, or `None` if there are fewer than
+    /// `n + 1` set bits.
+    pub fn select(&self, n: u32) -> Option<u8> {
+        let mut bits = self.0;
+
+        for _ in 0..n {
+            if bits == B::zero() {
+                return None
+            }
+
+            bits = bits & bits.wrapping_sub(B::one());
+        }
+
+        if bits == B::zero() {
+            None
+        } else {
+            Some(bits.trailing_zeros() as u8)
+        }
+    }
+
+    /// Set every bit in `range` to `bit` in a single masked operation.
+    pub fn set_range(&mut self, range: impl RangeBounds<u8>, bit: bool) {
+        let mask = Self::range_mask(range);
+
+        if bit {
+            self.0 |= mask;
+        } else {
+            self.0 &= !mask;
+        }
+    }
+
+    /// Set every bit in `range` to 1 in a single masked operation.
+    ///
+    /// Equivalent to `set_range(range, true)`; named after the analogous operation in Roaring's
+    /// container API.
+    pub fn insert_range(&mut self, range: impl RangeBounds<u8>) {
+        self.set_range(range, true);
+    }
+
+    /// Flip every bit in `range` in a single masked operation.
+    pub fn flip_range(&mut self, range: impl RangeBounds<u8>) {
+        let mask = Self::range_mask(range);
+        self.0 ^= mask;
+    }
+
+    /// Build a mask with every bit in `[start, end)` set to 1.
+    fn range_mask(range: impl RangeBounds<u8>) -> B {
+        let start = match range.start_bound() {
+            Bound::Included(&start) => start,
+            Bound::Excluded(&start) => start + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&end) => end + 1,
+            Bound::Excluded(&end) => end,
+            Bound::Unbounded => B::max_len(),
+        };
+
+        let high = if end >= B::max_len() {
+            B::max()
+        } else {
+            B::one_at_index(end).wrapping_sub(B::one())
+        };
+        let low = if start >= B::max_len() {
+            B::max()
+        } else {
+            B::one_at_index(start).wrapping_sub(B::one())
+        };
+
+        high & !low
+    }
+
+    /// Serialize the raw bits of this array to little-endian bytes.
+    ///
+    /// Always produces [Base::byte_len] bytes, independent of how many of them are logically
+    /// meaningful to the caller.
+    pub fn to_bytes(&self) -> Vec<u8> {
+        self.0.to_le_bytes()
+    }
+
+    /// Reconstruct a BitArray from little-endian bytes produced by [Self::to_bytes].
+    ///
+    /// Only the low `bit_len` bits are read; any remaining bits of the array default to 0.
+    /// `bytes` may be shorter than [Base::byte_len] as long as it covers `bit_len` bits; missing
+    /// bytes are treated as 0.
+    pub fn from_bytes(bytes: &[u8], bit_len: usize) -> Self {
+        let mut padded = vec![0u8; B::byte_len()];
+        let copy_len = bytes.len().min(padded.len());
+        padded[..copy_len].copy_from_slice(&bytes[..copy_len]);
+
+        let mut arr = BitArray(B::from_le_bytes(&padded));
+        arr.set_range(bit_len.min(B::max_len() as usize) as u8.., false);
+        arr
+    }
+
+    /// Serialize the low `bit_len` bits of this array as an SSZ-style bitlist: the bits
+    /// little-endian, followed by a single delimiter bit one position past the last logical bit.
+    /// The delimiter lets [Self::from_bitlist_bytes] recover `bit_len` without storing it
+    /// separately, and the result is only as many bytes as `bit_len` needs (not [Base::byte_len]).
+    ///
+    /// Panics if `bit_len >= Base::max_len`, since there must be room for the delimiter bit.
+    pub fn to_bitlist_bytes(&self, bit_len: usize) -> Vec<u8> {
+        assert!(bit_len < B::max_len() as usize, "bit_len must leave room for the delimiter bit");
+
+        let mut arr = *self;
+        arr.set_range(bit_len as u8.., false);
+        arr.set(bit_len as u8, true);
+
+        let byte_len = bit_len / 8 + 1;
+        arr.to_bytes()[..byte_len].to_vec()
+    }
+
+    /// Decode an SSZ-style bitlist produced by [Self::to_bitlist_bytes], returning the array and
+    /// its logical bit length.
+    pub fn from_bitlist_bytes(bytes: &[u8]) -> Result<(Self, usize), BitlistError> {
+        if bytes.len() > B::byte_len() {
+            return Err(BitlistError::TooManyBytes)
+        }
+
+        let padded_bit_len = bytes.len() * 8;
+        let padded = Self::from_bytes(bytes, padded_bit_len);
+
+        let delimiter = (0..B::max_len())
+            .rev()
+            .find(|&index| padded.get(index))
+            .ok_or(BitlistError::MissingDelimiter)?;
+
+        let mut arr = padded;
+        arr.set(delimiter, false);
+
+        Ok((arr, delimiter as usize))
+    }
+}
+
+/// An error returned when decoding an SSZ-style bitlist fails.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub enum BitlistError {
+    /// No delimiter bit was found, i.e. every bit in the input was 0.
+    MissingDelimiter,
+    /// The input held more bytes than a single array base can represent.
+    TooManyBytes
+}
+
+impl Display for BitlistError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            BitlistError::MissingDelimiter => write!(f, "no delimiter bit found in bitlist bytes"),
+            BitlistError::TooManyBytes => write!(f, "bitlist bytes exceed the base's capacity")
+        }
+    }
+}
+
+impl std::error::Error for BitlistError {}
+
+impl<B> BitAnd for BitArray<B> where B: Base {
+    type Output = Self;
+
+    fn bitand(self, rhs: Self) -> Self::Output {
+        BitArray(self.0 & rhs.0)
+    }
+}
+
+impl<B> BitAndAssign for BitArray<B> where B: Base {
+    fn bitand_assign(&mut self, rhs: Self) {
+        self.0 &= rhs.0;
+    }
+}
+
+impl<B> BitOr for BitArray<B> where B: Base {
+    type Output = Self;
+
+    fn bitor(self, rhs: Self) -> Self::Output {
+        BitArray(self.0 | rhs.0)
+    }
+}
+
+impl<B> BitOrAssign for BitArray<B> where B: Base {
+    fn bitor_assign(&mut self, rhs: Self) {
+        self.0 |= rhs.0;
+    }
+}
+
+impl<B> BitXor for BitArray<B> where B: Base {
+    type Output = Self;
+
+    fn bitxor(self, rhs: Self) -> Self::Output {
+        BitArray(self.0 ^ rhs.0)
+    }
+}
+
+impl<B> BitXorAssign for BitArray<B> where B: Base {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        self.0 ^= rhs.0;
+    }
+}
+
+impl<B> Not for BitArray<B> where B: Base {
+    type Output = Self;
+
+    fn not(self) -> Self::Output {
+        BitArray(!self.0)
+    }
 }
 
 impl<B> Display for BitArray<B> where B: Base {
@@ -118,16 +362,18 @@ impl<B> Iterator for BitArrayIter<B> where B: Base {
 }
 
 /// An iterator yielding the indexes of all 1 values of a [BitArray];
+///
+/// Instead of testing every bit in order, this walks only the set bits by
+/// repeatedly taking the position of the lowest one (`trailing_zeros`) and
+/// clearing it, so iteration costs O(popcount) rather than O(max_len).
 pub struct Ones<B: Base> {
-    counter: u8,
-    inner: BitArrayIter<B>
+    bits: B
 }
 
 impl<B> Ones<B> where B: Base {
     fn new(array: BitArray<B>) -> Self {
         Ones {
-            counter: 0,
-            inner: BitArrayIter::new(array)
+            bits: array.0
         }
     }
 }
@@ -136,33 +382,29 @@ impl<B> Iterator for Ones<B> where B: Base {
     type Item = u8;
 
     fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            if self.counter == B::max_len() {
-                break None
-            }
-
-            let elem = self.inner.next();
-            let index = self.counter;
-            self.counter += 1;
-
-            if let Some(bit) = elem && bit == true {
-                break Some(index)
-            }
+        if self.bits == B::zero() {
+            return None
         }
+
+        let index = self.bits.trailing_zeros() as u8;
+        self.bits = self.bits & self.bits.wrapping_sub(B::one());
+        Some(index)
     }
 }
 
 /// An iterator yielding the indexes of all 0 values of a [BitArray];
+///
+/// Implemented like [Ones], but over the complement of the array, masked to
+/// the valid `max_len` range so bits beyond the array's width aren't
+/// reported as zeroes.
 pub struct Zeroes<B: Base> {
-    counter: u8,
-    inner: BitArrayIter<B>
+    bits: B
 }
 
 impl<B> Zeroes<B> where B: Base {
     fn new(array: BitArray<B>) -> Self {
         Zeroes {
-            counter: 0,
-            inner: BitArrayIter::new(array)
+            bits: !array.0 & B::max()
         }
     }
 }
@@ -171,19 +413,13 @@ impl<B> Iterator for Zeroes<B> where B: Base {
     type Item = u8;
 
     fn next(&mut self) -> Option<Self::Item> {
-        loop {
-            if self.counter == B::max_len() {
-                break None
-            }
-
-            let elem = self.inner.next();
-            let index = self.counter;
-            self.counter += 1;
-
-            if let Some(bit) = elem && bit == false {
-                break Some(index)
-            }
+        if self.bits == B::zero() {
+            return None
         }
+
+        let index = self.bits.trailing_zeros() as u8;
+        self.bits = self.bits & self.bits.wrapping_sub(B::one());
+        Some(index)
     }
 }
 
@@ -194,9 +430,12 @@ pub trait Base:
     + Display
     + Binary
     + Not<Output=Self>
+    + BitOr<Output = Self>
     + BitOrAssign
     + BitAnd<Output = Self>
     + BitAndAssign
+    + BitXor<Output = Self>
+    + BitXorAssign
     + Shr<Output = Self>
     + PartialOrd
     + sealed::BaseSealed {
@@ -214,6 +453,29 @@ pub trait Base:
 
     /// Return the representation of a one at the given index for this base
     fn one_at_index(index: u8) -> Self;
+
+    /// Return the number of trailing zero bits, i.e. the index of the lowest set bit.
+    /// Returns [Self::max_len] if no bit is set.
+    fn trailing_zeros(self) -> u32;
+
+    /// Return `self - other`, wrapping around on underflow instead of panicking.
+    fn wrapping_sub(self, other: Self) -> Self;
+
+    /// Return the number of bits set to 1, using the hardware popcount intrinsic.
+    fn count_ones(self) -> u32;
+
+    /// Return the number of bytes needed to hold [Self::max_len] bits.
+    fn byte_len() -> usize {
+        (Self::max_len() as usize).div_ceil(8)
+    }
+
+    /// Return the little-endian byte representation of this base.
+    fn to_le_bytes(self) -> Vec<u8>;
+
+    /// Build a base from its little-endian byte representation.
+    ///
+    /// `bytes` must be exactly [Self::byte_len] long.
+    fn from_le_bytes(bytes: &[u8]) -> Self;
 }
 
 impl sealed::BaseSealed for u8 {}
@@ -237,6 +499,26 @@ impl Base for u8 {
     fn one_at_index(index: u8) -> Self {
         1 << index
     }
+
+    fn trailing_zeros(self) -> u32 {
+        u8::trailing_zeros(self)
+    }
+
+    fn wrapping_sub(self, other: Self) -> Self {
+        u8::wrapping_sub(self, other)
+    }
+
+    fn count_ones(self) -> u32 {
+        u8::count_ones(self)
+    }
+
+    fn to_le_bytes(self) -> Vec<u8> {
+        u8::to_le_bytes(self).to_vec()
+    }
+
+    fn from_le_bytes(bytes: &[u8]) -> Self {
+        u8::from_le_bytes(bytes.try_into().expect("bytes must be exactly byte_len() long"))
+    }
 }
 
 impl sealed::BaseSealed for u16 {}
@@ -260,6 +542,26 @@ impl Base for u16 {
     fn one_at_index(index: u8) -> Self {
         1 << index
     }
+
+    fn trailing_zeros(self) -> u32 {
+        u16::trailing_zeros(self)
+    }
+
+    fn wrapping_sub(self, other: Self) -> Self {
+        u16::wrapping_sub(self, other)
+    }
+
+    fn count_ones(self) -> u32 {
+        u16::count_ones(self)
+    }
+
+    fn to_le_bytes(self) -> Vec<u8> {
+        u16::to_le_bytes(self).to_vec()
+    }
+
+    fn from_le_bytes(bytes: &[u8]) -> Self {
+        u16::from_le_bytes(bytes.try_into().expect("bytes must be exactly byte_len() long"))
+    }
 }
 
 impl sealed::BaseSealed for u32 {}
@@ -283,6 +585,26 @@ impl Base for u32 {
     fn one_at_index(index: u8) -> Self {
         1 << index
     }
+
+    fn trailing_zeros(self) -> u32 {
+        u32::trailing_zeros(self)
+    }
+
+    fn wrapping_sub(self, other: Self) -> Self {
+        u32::wrapping_sub(self, other)
+    }
+
+    fn count_ones(self) -> u32 {
+        u32::count_ones(self)
+    }
+
+    fn to_le_bytes(self) -> Vec<u8> {
+        u32::to_le_bytes(self).to_vec()
+    }
+
+    fn from_le_bytes(bytes: &[u8]) -> Self {
+        u32::from_le_bytes(bytes.try_into().expect("bytes must be exactly byte_len() long"))
+    }
 }
 
 impl sealed::BaseSealed for u64 {}
@@ -306,6 +628,26 @@ impl Base for u64 {
     fn one_at_index(index: u8) -> Self {
         1 << index
     }
+
+    fn trailing_zeros(self) -> u32 {
+        u64::trailing_zeros(self)
+    }
+
+    fn wrapping_sub(self, other: Self) -> Self {
+        u64::wrapping_sub(self, other)
+    }
+
+    fn count_ones(self) -> u32 {
+        u64::count_ones(self)
+    }
+
+    fn to_le_bytes(self) -> Vec<u8> {
+        u64::to_le_bytes(self).to_vec()
+    }
+
+    fn from_le_bytes(bytes: &[u8]) -> Self {
+        u64::from_le_bytes(bytes.try_into().expect("bytes must be exactly byte_len() long"))
+    }
 }
 
 impl sealed::BaseSealed for u128 {}
@@ -329,6 +671,26 @@ impl Base for u128 {
     fn one_at_index(index: u8) -> Self {
         1 << index
     }
+
+    fn trailing_zeros(self) -> u32 {
+        u128::trailing_zeros(self)
+    }
+
+    fn wrapping_sub(self, other: Self) -> Self {
+        u128::wrapping_sub(self, other)
+    }
+
+    fn count_ones(self) -> u32 {
+        u128::count_ones(self)
+    }
+
+    fn to_le_bytes(self) -> Vec<u8> {
+        u128::to_le_bytes(self).to_vec()
+    }
+
+    fn from_le_bytes(bytes: &[u8]) -> Self {
+        u128::from_le_bytes(bytes.try_into().expect("bytes must be exactly byte_len() long"))
+    }
 }
 
 mod sealed {
@@ -336,9 +698,208 @@ mod sealed {
     pub trait BaseSealed {}
 }
 
+/// A growable bit vector, backed by a `Vec` of [BitArray] blocks.
+///
+/// [BitArray] is capped at [Base::max_len] bits. `BitVec` lifts that cap by chaining as many
+/// blocks as needed, while reusing `BitArray`'s per-word logic (and its set-algebra operators)
+/// for every block.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct BitVec<B: Base> {
+    blocks: Vec<BitArray<B>>,
+    len: usize
+}
+
+impl<B> BitVec<B> where B: Base {
+    /// Create a BitVec of exactly `bits` bits, all initialized to 0.
+    ///
+    /// Note this is unlike `Vec::with_capacity`: the vector is immediately `bits` bits long
+    /// (rounded up to full blocks), not empty with reserved storage.
+    pub fn zeroed(bits: usize) -> Self {
+        BitVec {
+            blocks: vec![BitArray::default(); Self::blocks_needed(bits)],
+            len: bits
+        }
+    }
+
+    /// Grow this BitVec so it can hold at least `bits` bits, appending zeroed blocks as needed.
+    ///
+    /// Does nothing if `bits` is not larger than the current length.
+    pub fn grow(&mut self, bits: usize) {
+        if bits <= self.len {
+            return
+        }
+
+        self.blocks.resize(Self::blocks_needed(bits), BitArray::default());
+        self.len = bits;
+    }
+
+    /// Return the number of bits this vector can currently hold.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Return true if this vector holds no bits.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Get the bit value of the vector at the given index.
+    pub fn get(&self, index: usize) -> bool {
+        let (block, bit) = Self::locate(index);
+        self.blocks[block].get(bit)
+    }
+
+    /// Set the bit at the given index to the given bit.
+    pub fn set(&mut self, index: usize, bit: bool) {
+        let (block, bit_index) = Self::locate(index);
+        self.blocks[block].set(bit_index, bit);
+    }
+
+    /// Creating an iterator over all the indexes set to 1.
+    pub fn ones(&self) -> impl Iterator<Item=usize> + '_ {
+        self.indexes_of(BitArray::ones)
+    }
+
+    /// Creating an iterator over all the indexes set to 0.
+    pub fn zeroes(&self) -> impl Iterator<Item=usize> + '_ {
+        self.indexes_of(BitArray::zeroes)
+    }
+
+    /// Return the number of bits set to 1 across the whole vector.
+    ///
+    /// Masks the last block down to [Self::len] rather than trusting that padding bits past
+    /// `len` are 0, so this stays correct even if an op left stray bits set there.
+    pub fn count_ones(&self) -> u32 {
+        match self.blocks.split_last() {
+            None => 0,
+            Some((last, rest)) => {
+                rest.iter().map(BitArray::count_ones).sum::<u32>() + last.rank(self.valid_bits_in_last_block())
+            }
+        }
+    }
+
+    /// Return the number of bits set to 0 across the whole vector.
+    pub fn count_zeros(&self) -> u32 {
+        self.len as u32 - self.count_ones()
+    }
+
+    /// Run `block_indexes` over every block and translate its per-block indexes into whole-vector
+    /// ones, discarding any padding bits past the logical length of the last block.
+    fn indexes_of<'a, I>(
+        &'a self,
+        block_indexes: impl Fn(&'a BitArray<B>) -> I + 'a
+    ) -> impl Iterator<Item=usize> + 'a where I: Iterator<Item=u8> + 'a {
+        let max_len = B::max_len() as usize;
+
+        self.blocks
+            .iter()
+            .enumerate()
+            .flat_map(move |(i, block)| block_indexes(block).map(move |bit| i * max_len + bit as usize))
+            .filter(move |&index| index < self.len)
+    }
+
+    fn blocks_needed(bits: usize) -> usize {
+        let max_len = B::max_len() as usize;
+        bits.div_ceil(max_len)
+    }
+
+    fn locate(index: usize) -> (usize, u8) {
+        let max_len = B::max_len() as usize;
+        (index / max_len, (index % max_len) as u8)
+    }
+
+    /// Return how many bits of the last block are within `len` (a full block unless `len` stops
+    /// partway through it).
+    fn valid_bits_in_last_block(&self) -> u8 {
+        let max_len = B::max_len() as usize;
+        let rem = self.len % max_len;
+        if rem == 0 { max_len as u8 } else { rem as u8 }
+    }
+
+    /// Clear any bits past [Self::len] in the last block, restoring the invariant that padding
+    /// bits are always 0.
+    fn mask_padding(&mut self) {
+        let valid = self.valid_bits_in_last_block();
+
+        if let Some(last) = self.blocks.last_mut() {
+            last.set_range(valid.., false);
+        }
+    }
+}
+
+/// Panics (in every build profile, not just debug) if `self` and `rhs` have different [BitVec::len].
+impl<B> BitAnd for BitVec<B> where B: Base {
+    type Output = Self;
+
+    fn bitand(mut self, rhs: Self) -> Self::Output {
+        self &= rhs;
+        self
+    }
+}
+
+/// Panics (in every build profile, not just debug) if `self` and `rhs` have different [BitVec::len].
+impl<B> BitAndAssign for BitVec<B> where B: Base {
+    fn bitand_assign(&mut self, rhs: Self) {
+        assert_eq!(self.len, rhs.len, "BitVecs must have the same length");
+
+        self.blocks.iter_mut().zip(rhs.blocks.iter()).for_each(|(a, b)| *a &= *b);
+    }
+}
+
+/// Panics (in every build profile, not just debug) if `self` and `rhs` have different [BitVec::len].
+impl<B> BitOr for BitVec<B> where B: Base {
+    type Output = Self;
+
+    fn bitor(mut self, rhs: Self) -> Self::Output {
+        self |= rhs;
+        self
+    }
+}
+
+/// Panics (in every build profile, not just debug) if `self` and `rhs` have different [BitVec::len].
+impl<B> BitOrAssign for BitVec<B> where B: Base {
+    fn bitor_assign(&mut self, rhs: Self) {
+        assert_eq!(self.len, rhs.len, "BitVecs must have the same length");
+
+        self.blocks.iter_mut().zip(rhs.blocks.iter()).for_each(|(a, b)| *a |= *b);
+    }
+}
+
+/// Panics (in every build profile, not just debug) if `self` and `rhs` have different [BitVec::len].
+impl<B> BitXor for BitVec<B> where B: Base {
+    type Output = Self;
+
+    fn bitxor(mut self, rhs: Self) -> Self::Output {
+        self ^= rhs;
+        self
+    }
+}
+
+/// Panics (in every build profile, not just debug) if `self` and `rhs` have different [BitVec::len].
+impl<B> BitXorAssign for BitVec<B> where B: Base {
+    fn bitxor_assign(&mut self, rhs: Self) {
+        assert_eq!(self.len, rhs.len, "BitVecs must have the same length");
+
+        self.blocks.iter_mut().zip(rhs.blocks.iter()).for_each(|(a, b)| *a ^= *b);
+    }
+}
+
+impl<B> Not for BitVec<B> where B: Base {
+    type Output = Self;
+
+    /// Flip every bit, including padding past [Self::len] in the last block, then re-mask that
+    /// padding back to 0 so the flipped padding does not show up after [Self::grow] or in
+    /// equality comparisons.
+    fn not(mut self) -> Self::Output {
+        self.blocks.iter_mut().for_each(|block| *block = !*block);
+        self.mask_padding();
+        self
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use crate::BitArray;
+    use crate::{BitArray, BitVec, BitlistError};
 
     #[test]
     fn new_works() {
@@ -389,4 +950,248 @@ mod tests {
 
         assert_eq!(arr.zeroes().collect::<Vec<_>>(), expected)
     }
+
+    #[test]
+    fn union_works() {
+        let a = BitArray::<u8>::new([true, false, true, false]);
+        let b = BitArray::<u8>::new([false, true, false, false]);
+
+        assert_eq!(a.union(&b), BitArray::new([true, true, true, false]));
+        assert_eq!(a | b, BitArray::new([true, true, true, false]));
+    }
+
+    #[test]
+    fn intersection_works() {
+        let a = BitArray::<u8>::new([true, true, false, false]);
+        let b = BitArray::<u8>::new([true, false, true, false]);
+
+        assert_eq!(a.intersection(&b), BitArray::new([true, false, false, false]));
+        assert_eq!(a & b, BitArray::new([true, false, false, false]));
+    }
+
+    #[test]
+    fn difference_works() {
+        let a = BitArray::<u8>::new([true, true, false, false]);
+        let b = BitArray::<u8>::new([true, false, true, false]);
+
+        assert_eq!(a.difference(&b), BitArray::new([false, true, false, false]));
+    }
+
+    #[test]
+    fn symmetric_difference_works() {
+        let a = BitArray::<u8>::new([true, true, false, false]);
+        let b = BitArray::<u8>::new([true, false, true, false]);
+
+        assert_eq!(a.symmetric_difference(&b), BitArray::new([false, true, true, false]));
+        assert_eq!(a ^ b, BitArray::new([false, true, true, false]));
+    }
+
+    #[test]
+    fn not_works() {
+        let a = BitArray::<u8>::new([true, false, true, false, false, false, false, false]);
+
+        assert_eq!(!a, BitArray::new([false, true, false, true, true, true, true, true]));
+    }
+
+    #[test]
+    fn assign_ops_work() {
+        let mut a = BitArray::<u8>::new([true, true, false, false]);
+        let b = BitArray::<u8>::new([true, false, true, false]);
+
+        a &= b;
+        assert_eq!(a, BitArray::new([true, false, false, false]));
+
+        let mut a = BitArray::<u8>::new([true, true, false, false]);
+        a |= b;
+        assert_eq!(a, BitArray::new([true, true, true, false]));
+
+        let mut a = BitArray::<u8>::new([true, true, false, false]);
+        a ^= b;
+        assert_eq!(a, BitArray::new([false, true, true, false]));
+    }
+
+    #[test]
+    fn count_ones_and_zeros_work() {
+        let arr = BitArray::<u8>::new([true, false, true, false, true, false, true, false]);
+
+        assert_eq!(arr.count_ones(), 4);
+        assert_eq!(arr.count_zeros(), 4);
+    }
+
+    #[test]
+    fn rank_works() {
+        let arr = BitArray::<u8>::new([true, false, true, false, true, false, true, false]);
+
+        assert_eq!(arr.rank(0), 0);
+        assert_eq!(arr.rank(2), 1);
+        assert_eq!(arr.rank(5), 3);
+        assert_eq!(arr.rank(8), 4);
+    }
+
+    #[test]
+    fn select_works() {
+        let arr = BitArray::<u8>::new([true, false, true, false, true, false, true, false]);
+
+        assert_eq!(arr.select(0), Some(0));
+        assert_eq!(arr.select(1), Some(2));
+        assert_eq!(arr.select(3), Some(6));
+        assert_eq!(arr.select(4), None);
+    }
+
+    #[test]
+    fn set_range_works() {
+        let mut arr = BitArray::<u8>::default();
+
+        arr.set_range(2..5, true);
+        assert_eq!(arr, BitArray::new([false, false, true, true, true, false, false, false]));
+
+        arr.set_range(3..=4, false);
+        assert_eq!(arr, BitArray::new([false, false, true, false, false, false, false, false]));
+
+        arr.set_range(.., true);
+        assert_eq!(arr, BitArray::all_one());
+    }
+
+    #[test]
+    fn insert_range_works() {
+        let mut arr = BitArray::<u8>::default();
+
+        arr.insert_range(2..5);
+        assert_eq!(arr, BitArray::new([false, false, true, true, true, false, false, false]));
+    }
+
+    #[test]
+    fn flip_range_works() {
+        let mut arr = BitArray::<u8>::new([true, false, true, false, true, false, true, false]);
+
+        arr.flip_range(2..6);
+        assert_eq!(arr, BitArray::new([true, false, false, true, false, true, true, false]));
+    }
+
+    #[test]
+    fn bitvec_zeroed_rounds_up_to_full_blocks() {
+        let vec = BitVec::<u8>::zeroed(10);
+
+        assert_eq!(vec.len(), 10);
+        assert_eq!(vec.blocks.len(), 2);
+    }
+
+    #[test]
+    fn bitvec_get_set_works_across_blocks() {
+        let mut vec = BitVec::<u8>::zeroed(16);
+
+        vec.set(0, true);
+        vec.set(9, true);
+
+        assert_eq!(vec.get(0), true);
+        assert_eq!(vec.get(8), false);
+        assert_eq!(vec.get(9), true);
+    }
+
+    #[test]
+    fn bitvec_grow_works() {
+        let mut vec = BitVec::<u8>::zeroed(4);
+        vec.set(3, true);
+
+        vec.grow(12);
+
+        assert_eq!(vec.len(), 12);
+        assert_eq!(vec.blocks.len(), 2);
+        assert_eq!(vec.get(3), true);
+        assert_eq!(vec.get(11), false);
+    }
+
+    #[test]
+    fn bitvec_ones_and_zeroes_work() {
+        let mut vec = BitVec::<u8>::zeroed(10);
+        vec.set(0, true);
+        vec.set(9, true);
+
+        assert_eq!(vec.ones().collect::<Vec<_>>(), vec![0, 9]);
+        assert_eq!(vec.zeroes().collect::<Vec<_>>(), vec![1, 2, 3, 4, 5, 6, 7, 8]);
+    }
+
+    #[test]
+    fn bitvec_count_ones_and_zeros_work() {
+        let mut vec = BitVec::<u8>::zeroed(10);
+        vec.set(0, true);
+        vec.set(9, true);
+
+        assert_eq!(vec.count_ones(), 2);
+        assert_eq!(vec.count_zeros(), 8);
+    }
+
+    #[test]
+    fn bitvec_bitwise_ops_work() {
+        let mut a = BitVec::<u8>::zeroed(16);
+        a.set(0, true);
+        a.set(8, true);
+
+        let mut b = BitVec::<u8>::zeroed(16);
+        b.set(0, true);
+        b.set(9, true);
+
+        assert_eq!((a.clone() & b.clone()).ones().collect::<Vec<_>>(), vec![0]);
+        assert_eq!((a.clone() | b.clone()).ones().collect::<Vec<_>>(), vec![0, 8, 9]);
+        assert_eq!((a.clone() ^ b.clone()).ones().collect::<Vec<_>>(), vec![8, 9]);
+    }
+
+    #[test]
+    #[should_panic(expected = "BitVecs must have the same length")]
+    fn bitvec_bitand_panics_on_length_mismatch() {
+        let a = BitVec::<u8>::zeroed(8);
+        let b = BitVec::<u8>::zeroed(16);
+
+        let _ = a & b;
+    }
+
+    #[test]
+    fn bitvec_not_masks_padding_past_len() {
+        let vec = BitVec::<u8>::zeroed(10);
+        let notted = !vec;
+
+        assert_eq!(notted.count_ones(), 10);
+        assert_eq!(notted.count_zeros(), 0);
+        assert_eq!(notted.ones().collect::<Vec<_>>(), (0..10).collect::<Vec<_>>());
+        assert_eq!(notted.blocks[1], BitArray::new([true, true]));
+    }
+
+    #[test]
+    fn to_bytes_and_from_bytes_roundtrip() {
+        let arr = BitArray::<u32>::new([true, false, true, false, true]);
+
+        let bytes = arr.to_bytes();
+        assert_eq!(bytes, vec![0b00010101, 0, 0, 0]);
+        assert_eq!(BitArray::<u32>::from_bytes(&bytes, 32), arr);
+    }
+
+    #[test]
+    fn from_bytes_ignores_bits_past_bit_len() {
+        let bytes = vec![0b11111111];
+
+        assert_eq!(BitArray::<u8>::from_bytes(&bytes, 4), BitArray::new([true, true, true, true]));
+    }
+
+    #[test]
+    fn to_bitlist_bytes_appends_delimiter() {
+        let arr = BitArray::<u8>::new([true, false, true]);
+
+        assert_eq!(arr.to_bitlist_bytes(3), vec![0b00001101]);
+    }
+
+    #[test]
+    fn bitlist_bytes_roundtrip() {
+        let arr = BitArray::<u32>::new([true, false, true, false, true, false, true, false, true, true]);
+
+        let bytes = arr.to_bitlist_bytes(10);
+        let (decoded, bit_len) = BitArray::<u32>::from_bitlist_bytes(&bytes).unwrap();
+
+        assert_eq!(bit_len, 10);
+        assert_eq!(decoded, arr);
+    }
+
+    #[test]
+    fn from_bitlist_bytes_without_delimiter_fails() {
+        assert_eq!(BitArray::<u8>::from_bitlist_bytes(&[0]), Err(BitlistError::MissingDelimiter));
+    }
 }
\ No newline at end of file